@@ -42,6 +42,8 @@ mod error;
 // mod net; - no need in encoding network addresses for lightning p2p protocol
 mod primitives;
 pub mod strategies;
+#[cfg(feature = "testkit")]
+pub mod testkit;
 
 // -----------------------------------------------------------------------------
 use std::io;
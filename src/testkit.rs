@@ -0,0 +1,539 @@
+// Network encoding for lightning network peer protocol data types
+// Written in 2020-2022 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Deterministic key material for tests and examples.
+//!
+//! [`Keyset::with`] derives all keys from a 32-byte seed, so the same seed
+//! always produces the same funding keys, basepoints and per-commitment points
+//! – and, as a result, byte-identical transactions. Per-commitment secrets
+//! follow the BOLT-3 `generate_from_seed` algorithm. [`Keyset::bolt3_local`]
+//! and [`Keyset::bolt3_remote`] reproduce keys from BOLT-3 appendix C, and
+//! [`derive_pubkey`], [`derive_privkey`], [`derive_revocation_pubkey`] and
+//! [`derive_revocation_privkey`] implement BOLT-3 key derivation for
+//! computing per-commitment keys out of the basepoints.
+//!
+//! NB: Never use this module for producing keys controlling real funds.
+
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::secp256k1::{PublicKey, Scalar, Secp256k1, SecretKey};
+
+/// Maximal commitment number (and per-commitment secret index) as defined by
+/// BOLT-3: commitment numbers are 48-bit values.
+pub const MAX_COMMITMENT_INDEX: u64 = 0xFFFF_FFFF_FFFF;
+
+/// Private keys used in BOLT-3 appendices.
+pub mod bolt3 {
+    /// `local_funding_privkey` from BOLT-3 appendix B & C.
+    pub const LOCAL_FUNDING_PRIVKEY: [u8; 32] = [
+        0x30, 0xff, 0x49, 0x56, 0xbb, 0xdd, 0x32, 0x22, 0xd4, 0x4c, 0xc5, 0xe8,
+        0xa1, 0x26, 0x1d, 0xab, 0x1e, 0x07, 0x95, 0x7b, 0xda, 0xc5, 0xae, 0x88,
+        0xfe, 0x32, 0x61, 0xef, 0x32, 0x1f, 0x37, 0x49,
+    ];
+
+    /// `remote_funding_privkey` from BOLT-3 appendix C.
+    pub const REMOTE_FUNDING_PRIVKEY: [u8; 32] = [
+        0x15, 0x52, 0xdf, 0xba, 0x4f, 0x6c, 0xf2, 0x9a, 0x62, 0xa0, 0xaf, 0x13,
+        0xc8, 0xd6, 0x98, 0x1d, 0x36, 0xd0, 0xef, 0x8d, 0x61, 0xba, 0x10, 0xfb,
+        0x0f, 0xe9, 0x0d, 0xa7, 0x63, 0x4d, 0x7e, 0x13,
+    ];
+
+    /// `local_payment_basepoint_secret` from BOLT-3 appendix C.
+    pub const LOCAL_PAYMENT_BASEPOINT_SECRET: [u8; 32] = [0x11; 32];
+
+    /// `remote_revocation_basepoint_secret` from BOLT-3 appendix C.
+    pub const REMOTE_REVOCATION_BASEPOINT_SECRET: [u8; 32] = [0x22; 32];
+
+    /// `local_delayed_payment_basepoint_secret` from BOLT-3 appendix C.
+    pub const LOCAL_DELAYED_PAYMENT_BASEPOINT_SECRET: [u8; 32] = [0x33; 32];
+
+    /// `remote_payment_basepoint_secret` from BOLT-3 appendix C.
+    pub const REMOTE_PAYMENT_BASEPOINT_SECRET: [u8; 32] = [0x44; 32];
+
+    /// `local_privkey` from BOLT-3 appendix C: the local payment basepoint
+    /// secret tweaked with the local per-commitment point.
+    pub const LOCAL_PRIVKEY: [u8; 32] = [
+        0xbb, 0x13, 0xb1, 0x21, 0xcd, 0xc3, 0x57, 0xcd, 0x2e, 0x60, 0x8b, 0x0a,
+        0xea, 0x29, 0x4a, 0xfc, 0xa3, 0x6e, 0x2b, 0x34, 0xcf, 0x95, 0x8e, 0x2e,
+        0x64, 0x51, 0xa2, 0xf2, 0x74, 0x69, 0x44, 0x91,
+    ];
+
+    /// `base_secret` from BOLT-3 appendix E (key derivation test vectors).
+    pub const BASE_SECRET: [u8; 32] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b,
+        0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+        0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+    ];
+
+    /// `per_commitment_secret` from BOLT-3 appendix E, which is also used as
+    /// `local_per_commitment_secret` in appendix C.
+    pub const PER_COMMITMENT_SECRET: [u8; 32] = [
+        0x1f, 0x1e, 0x1d, 0x1c, 0x1b, 0x1a, 0x19, 0x18, 0x17, 0x16, 0x15, 0x14,
+        0x13, 0x12, 0x11, 0x10, 0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, 0x08,
+        0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x00,
+    ];
+}
+
+/// Computes `SHA256(a || b)` as a secp256k1 scalar.
+fn tweak(a: &PublicKey, b: &PublicKey) -> Scalar {
+    let mut engine = sha256::Hash::engine();
+    engine.input(&a.serialize());
+    engine.input(&b.serialize());
+    let hash = sha256::Hash::from_engine(engine);
+    Scalar::from_be_bytes(hash.into_inner())
+        .expect("negligible probability of hash exceeding curve order")
+}
+
+/// Derives secret key from a seed and a text label as `SHA256(seed || label)`.
+///
+/// # Panics
+///
+/// If the hash value is not a valid secp256k1 secret key, which happens with
+/// negligible probability.
+pub fn derive_secret(seed: [u8; 32], label: &str) -> SecretKey {
+    let mut engine = sha256::Hash::engine();
+    engine.input(&seed);
+    engine.input(label.as_bytes());
+    let hash = sha256::Hash::from_engine(engine);
+    SecretKey::from_slice(&hash[..])
+        .expect("negligible probability of invalid secret key")
+}
+
+/// Derives per-commitment public key (`localpubkey`, `remotepubkey`,
+/// `local_htlcpubkey`, `remote_htlcpubkey` or `local_delayedpubkey`) from the
+/// corresponding `basepoint` as defined in BOLT-3:
+/// `basepoint + SHA256(per_commitment_point || basepoint) * G`.
+pub fn derive_pubkey(
+    basepoint: PublicKey,
+    per_commitment_point: PublicKey,
+) -> PublicKey {
+    basepoint
+        .add_exp_tweak(
+            &Secp256k1::verification_only(),
+            &tweak(&per_commitment_point, &basepoint),
+        )
+        .expect("negligible probability of tweak producing point at infinity")
+}
+
+/// Derives private key matching [`derive_pubkey`] from the basepoint secret:
+/// `basepoint_secret + SHA256(per_commitment_point || basepoint)`.
+pub fn derive_privkey(
+    basepoint_secret: SecretKey,
+    per_commitment_point: PublicKey,
+) -> SecretKey {
+    let basepoint = PublicKey::from_secret_key(
+        &Secp256k1::signing_only(),
+        &basepoint_secret,
+    );
+    basepoint_secret
+        .add_tweak(&tweak(&per_commitment_point, &basepoint))
+        .expect("negligible probability of tweak producing zero key")
+}
+
+/// Derives `revocationpubkey` as defined in BOLT-3:
+/// `R * SHA256(R || P) + P * SHA256(P || R)`, where `R` is the
+/// `revocation_basepoint` and `P` is the `per_commitment_point`.
+pub fn derive_revocation_pubkey(
+    revocation_basepoint: PublicKey,
+    per_commitment_point: PublicKey,
+) -> PublicKey {
+    let secp = Secp256k1::verification_only();
+    let revocation_part = revocation_basepoint
+        .mul_tweak(&secp, &tweak(&revocation_basepoint, &per_commitment_point))
+        .expect("negligible probability of invalid tweak");
+    let commitment_part = per_commitment_point
+        .mul_tweak(&secp, &tweak(&per_commitment_point, &revocation_basepoint))
+        .expect("negligible probability of invalid tweak");
+    revocation_part
+        .combine(&commitment_part)
+        .expect("negligible probability of point at infinity")
+}
+
+/// Derives `revocationprivkey` matching [`derive_revocation_pubkey`] from the
+/// revocation basepoint secret and per-commitment secret.
+pub fn derive_revocation_privkey(
+    revocation_basepoint_secret: SecretKey,
+    per_commitment_secret: SecretKey,
+) -> SecretKey {
+    let secp = Secp256k1::signing_only();
+    let revocation_basepoint =
+        PublicKey::from_secret_key(&secp, &revocation_basepoint_secret);
+    let per_commitment_point =
+        PublicKey::from_secret_key(&secp, &per_commitment_secret);
+    let revocation_part = revocation_basepoint_secret
+        .mul_tweak(&tweak(&revocation_basepoint, &per_commitment_point))
+        .expect("negligible probability of invalid tweak");
+    let commitment_part = per_commitment_secret
+        .mul_tweak(&tweak(&per_commitment_point, &revocation_basepoint))
+        .expect("negligible probability of invalid tweak");
+    revocation_part
+        .add_tweak(&Scalar::from(commitment_part))
+        .expect("negligible probability of zero key")
+}
+
+/// Computes per-commitment secret with a given `index` from the shachain
+/// `seed` using BOLT-3 `generate_from_seed` algorithm. Only lower 48 bits of
+/// the `index` are used.
+pub fn per_commitment_secret(seed: [u8; 32], index: u64) -> [u8; 32] {
+    let mut p = seed;
+    for bit in (0..48).rev() {
+        if index & (1 << bit) != 0 {
+            p[bit / 8] ^= 1 << (bit % 8);
+            p = sha256::Hash::hash(&p).into_inner();
+        }
+    }
+    p
+}
+
+/// Computes per-commitment point with a given `index` from the shachain
+/// `seed`; see [`per_commitment_secret`].
+///
+/// # Panics
+///
+/// If the per-commitment secret is not a valid secp256k1 secret key, which
+/// happens with negligible probability.
+pub fn per_commitment_point(seed: [u8; 32], index: u64) -> PublicKey {
+    secret_to_point(per_commitment_secret(seed, index))
+}
+
+fn secret_to_point(secret: [u8; 32]) -> PublicKey {
+    let secret = SecretKey::from_slice(&secret)
+        .expect("negligible probability of invalid secret key");
+    PublicKey::from_secret_key(&Secp256k1::signing_only(), &secret)
+}
+
+fn bolt3_key(secret: [u8; 32]) -> SecretKey {
+    SecretKey::from_slice(&secret).expect("BOLT-3 test vector key")
+}
+
+/// Set of channel private keys used by one of the channel parties.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Keyset {
+    /// Funding private key
+    pub funding_key: SecretKey,
+    /// Revocation basepoint secret
+    pub revocation_basepoint: SecretKey,
+    /// Payment basepoint secret
+    pub payment_basepoint: SecretKey,
+    /// Delayed payment basepoint secret
+    pub delayed_payment_basepoint: SecretKey,
+    /// HTLC basepoint secret
+    pub htlc_basepoint: SecretKey,
+    /// Seed for generating per-commitment secrets
+    pub shachain_seed: [u8; 32],
+    // Per-commitment secret used for all commitments instead of the ones
+    // generated from the shachain seed. Set only by `Keyset::bolt3_local`,
+    // since BOLT-3 test vectors use a single fixed per-commitment secret.
+    fixed_per_commitment_secret: Option<[u8; 32]>,
+}
+
+/// Public part of a [`Keyset`], as it is sent to the remote peer in
+/// `open_channel` and `accept_channel` messages.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Pubkeys {
+    /// Funding public key
+    pub funding_pubkey: PublicKey,
+    /// Revocation basepoint
+    pub revocation_basepoint: PublicKey,
+    /// Payment basepoint
+    pub payment_basepoint: PublicKey,
+    /// Delayed payment basepoint
+    pub delayed_payment_basepoint: PublicKey,
+    /// HTLC basepoint
+    pub htlc_basepoint: PublicKey,
+    /// Per-commitment point for the first commitment transaction
+    pub first_per_commitment_point: PublicKey,
+}
+
+impl Keyset {
+    /// Derives all keys from a given `seed`.
+    pub fn with(seed: [u8; 32]) -> Keyset {
+        Keyset {
+            funding_key: derive_secret(seed, "funding"),
+            revocation_basepoint: derive_secret(seed, "revocation"),
+            payment_basepoint: derive_secret(seed, "payment"),
+            delayed_payment_basepoint: derive_secret(seed, "delayed_payment"),
+            htlc_basepoint: derive_secret(seed, "htlc"),
+            shachain_seed: derive_secret(seed, "shachain").secret_bytes(),
+            fixed_per_commitment_secret: None,
+        }
+    }
+
+    /// Local node keyset from BOLT-3 appendix C, using the fixed local
+    /// per-commitment secret for all commitments.
+    ///
+    /// Appendix C uses the payment basepoint for HTLC outputs and does not
+    /// define a local revocation basepoint, so both of them are set to the
+    /// local payment basepoint secret.
+    pub fn bolt3_local() -> Keyset {
+        let payment_basepoint =
+            bolt3_key(bolt3::LOCAL_PAYMENT_BASEPOINT_SECRET);
+        Keyset {
+            funding_key: bolt3_key(bolt3::LOCAL_FUNDING_PRIVKEY),
+            revocation_basepoint: payment_basepoint,
+            payment_basepoint,
+            delayed_payment_basepoint: bolt3_key(
+                bolt3::LOCAL_DELAYED_PAYMENT_BASEPOINT_SECRET,
+            ),
+            htlc_basepoint: payment_basepoint,
+            shachain_seed: [0u8; 32],
+            fixed_per_commitment_secret: Some(bolt3::PER_COMMITMENT_SECRET),
+        }
+    }
+
+    /// Remote node keyset from BOLT-3 appendix C.
+    ///
+    /// Appendix C uses the payment basepoint for HTLC outputs and does not
+    /// define a remote delayed payment basepoint, so both of them are set to
+    /// the remote payment basepoint secret. Remote per-commitment points are
+    /// not defined either; they are generated from a zero shachain seed.
+    pub fn bolt3_remote() -> Keyset {
+        let payment_basepoint =
+            bolt3_key(bolt3::REMOTE_PAYMENT_BASEPOINT_SECRET);
+        Keyset {
+            funding_key: bolt3_key(bolt3::REMOTE_FUNDING_PRIVKEY),
+            revocation_basepoint: bolt3_key(
+                bolt3::REMOTE_REVOCATION_BASEPOINT_SECRET,
+            ),
+            payment_basepoint,
+            delayed_payment_basepoint: payment_basepoint,
+            htlc_basepoint: payment_basepoint,
+            shachain_seed: [0u8; 32],
+            fixed_per_commitment_secret: None,
+        }
+    }
+
+    /// Per-commitment secret for the commitment with a given `index`. For
+    /// [`Keyset::bolt3_local`] this is always the appendix C secret.
+    #[inline]
+    pub fn per_commitment_secret(&self, index: u64) -> [u8; 32] {
+        self.fixed_per_commitment_secret
+            .unwrap_or_else(|| per_commitment_secret(self.shachain_seed, index))
+    }
+
+    /// Per-commitment point for the commitment with a given `index`.
+    #[inline]
+    pub fn per_commitment_point(&self, index: u64) -> PublicKey {
+        secret_to_point(self.per_commitment_secret(index))
+    }
+
+    /// Computes public keys for the keyset. The first per-commitment point
+    /// uses [`MAX_COMMITMENT_INDEX`], since BOLT-3 commitment indexes are
+    /// counted down.
+    pub fn pubkeys(&self) -> Pubkeys {
+        let secp = Secp256k1::signing_only();
+        Pubkeys {
+            funding_pubkey: PublicKey::from_secret_key(
+                &secp,
+                &self.funding_key,
+            ),
+            revocation_basepoint: PublicKey::from_secret_key(
+                &secp,
+                &self.revocation_basepoint,
+            ),
+            payment_basepoint: PublicKey::from_secret_key(
+                &secp,
+                &self.payment_basepoint,
+            ),
+            delayed_payment_basepoint: PublicKey::from_secret_key(
+                &secp,
+                &self.delayed_payment_basepoint,
+            ),
+            htlc_basepoint: PublicKey::from_secret_key(
+                &secp,
+                &self.htlc_basepoint,
+            ),
+            first_per_commitment_point: self
+                .per_commitment_point(MAX_COMMITMENT_INDEX),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use bitcoin::hashes::hex::FromHex;
+
+    use super::*;
+
+    fn pk(hex: &str) -> PublicKey {
+        PublicKey::from_str(hex).unwrap()
+    }
+
+    fn sk(hex: &str) -> SecretKey {
+        SecretKey::from_str(hex).unwrap()
+    }
+
+    fn bytes32(hex: &str) -> [u8; 32] {
+        let mut buf = [0u8; 32];
+        buf.copy_from_slice(&Vec::<u8>::from_hex(hex).unwrap());
+        buf
+    }
+
+    // Test vectors taken from
+    // https://github.com/lightning/bolts/blob/master/03-transactions.md#appendix-c-commitment-and-htlc-transaction-test-vectors
+    #[test]
+    fn bolt3_keysets() {
+        let local = Keyset::bolt3_local().pubkeys();
+        let remote = Keyset::bolt3_remote().pubkeys();
+        let per_commitment_point = local.first_per_commitment_point;
+
+        assert_eq!(
+            local.funding_pubkey,
+            pk("023da092f6980e58d2c037173180e9a465476026ee50f96695963e8efe436f54eb")
+        );
+        assert_eq!(
+            remote.funding_pubkey,
+            pk("030e9f7b623d2ccc7c9bd44d66d5ce21ce504c0acf6385a132cec6d3c39fa711c1")
+        );
+        assert_eq!(
+            remote.revocation_basepoint,
+            pk("02466d7fcae563e5cb09a0d1870bb580344804617879a14949cf22285f1bae3f27")
+        );
+        assert_eq!(
+            local.delayed_payment_basepoint,
+            pk("023c72addb4fdf09af94f0c94d7fe92a386a7e70cf8a1d85916386bb2535c7b1b1")
+        );
+        assert_eq!(
+            per_commitment_point,
+            pk("025f7117a78150fe2ef97db7cfc83bd57b2e2c0d0dd25eaf467a4a1c2a45ce1486")
+        );
+        assert_eq!(
+            Keyset::bolt3_local().per_commitment_point(42),
+            per_commitment_point
+        );
+
+        assert_eq!(
+            derive_privkey(
+                Keyset::bolt3_local().payment_basepoint,
+                per_commitment_point
+            ),
+            SecretKey::from_slice(&bolt3::LOCAL_PRIVKEY).unwrap()
+        );
+        assert_eq!(
+            derive_pubkey(local.payment_basepoint, per_commitment_point),
+            pk("030d417a46946384f88d5f3337267c5e579765875dc4daca813e21734b140639e7")
+        );
+        assert_eq!(
+            derive_pubkey(remote.payment_basepoint, per_commitment_point),
+            pk("0394854aa6eab5b2a8122cc726e9dded053a2184d88256816826d6231c068d4a5b")
+        );
+        assert_eq!(
+            derive_privkey(
+                Keyset::bolt3_local().delayed_payment_basepoint,
+                per_commitment_point
+            ),
+            sk("adf3464ce9c2f230fd2582fda4c6965e4993ca5524e8c9580e3df0cf226981ad")
+        );
+        assert_eq!(
+            derive_revocation_pubkey(
+                remote.revocation_basepoint,
+                per_commitment_point
+            ),
+            pk("0212a140cd0c6539d07cd08dfe09984dec3251ea808b892efeac3ede9402bf2b19")
+        );
+    }
+
+    // Test vectors taken from
+    // https://github.com/lightning/bolts/blob/master/03-transactions.md#appendix-e-key-derivation-test-vectors
+    #[test]
+    fn key_derivation() {
+        let base_secret = SecretKey::from_slice(&bolt3::BASE_SECRET).unwrap();
+        let per_commitment_secret =
+            SecretKey::from_slice(&bolt3::PER_COMMITMENT_SECRET).unwrap();
+        let base_point = secret_to_point(bolt3::BASE_SECRET);
+        let per_commitment_point =
+            secret_to_point(bolt3::PER_COMMITMENT_SECRET);
+
+        assert_eq!(
+            base_point,
+            pk("036d6caac248af96f6afa7f904f550253a0f3ef3f5aa2fe6838a95b216691468e2")
+        );
+        assert_eq!(
+            per_commitment_point,
+            pk("025f7117a78150fe2ef97db7cfc83bd57b2e2c0d0dd25eaf467a4a1c2a45ce1486")
+        );
+        assert_eq!(
+            derive_pubkey(base_point, per_commitment_point),
+            pk("0235f2dbfaa89b57ec7b055afe29849ef7ddfeb1cefdb9ebdc43f5494984db29e5")
+        );
+        assert_eq!(
+            derive_privkey(base_secret, per_commitment_point),
+            sk("cbced912d3b21bf196a766651e436aff192362621ce317704ea2f75d87e7be0f")
+        );
+        assert_eq!(
+            derive_revocation_pubkey(base_point, per_commitment_point),
+            pk("02916e326636d19c33f13e8c0c3a03dd157f332f3e99c317c141dd865eb01f8ff0")
+        );
+        assert_eq!(
+            derive_revocation_privkey(base_secret, per_commitment_secret),
+            sk("d09ffff62ddb2297ab000cc85bcb4283fdeb6aa052affbc9dddcf33b61078110")
+        );
+    }
+
+    // Test vectors taken from
+    // https://github.com/lightning/bolts/blob/master/03-transactions.md#generation-tests
+    #[test]
+    fn generate_from_seed() {
+        assert_eq!(
+            per_commitment_secret([0u8; 32], MAX_COMMITMENT_INDEX),
+            bytes32(
+                "02a40c85b6f28da08dfdbe0926c53fab2de6d28c10301f8f7c4073d5e42e3148"
+            )
+        );
+        assert_eq!(
+            per_commitment_secret([0xFFu8; 32], MAX_COMMITMENT_INDEX),
+            bytes32(
+                "7cc854b54e3e0dcdb010d7a3fee464a9687be6e8db3be6854c475621e007a5dc"
+            )
+        );
+        assert_eq!(
+            per_commitment_secret([0xFFu8; 32], 0xaaaaaaaaaaa),
+            bytes32(
+                "56f4008fb007ca9acf0e15b054d5c9fd12ee06cea347914ddbaed70d1c13a528"
+            )
+        );
+        assert_eq!(
+            per_commitment_secret([0x01u8; 32], 1),
+            bytes32(
+                "915c75942a26bb3a433a8ce2cb0427c29ec6c1775cfc78328b57f6ba7bfeaa9c"
+            )
+        );
+    }
+
+    // Regression vector: if it changes, transactions produced with the same
+    // seed are no longer reproducible
+    #[test]
+    fn keyset_from_seed() {
+        assert_eq!(Keyset::with([0x42u8; 32]).pubkeys(), Pubkeys {
+            funding_pubkey: pk(
+                "027c8256e8f15bbd2a3fce56e4cc37b3fe6fc563b54d9acaecf0d238ed075e2f79"
+            ),
+            revocation_basepoint: pk(
+                "02d323ddb83db34bd8e2cfe1bc86ab781d78c94be784d39c2d9ce717a08e927361"
+            ),
+            payment_basepoint: pk(
+                "031453e989ce1b95a97fa29058847d88254c716db110d2b24e07eba2b2203e24ff"
+            ),
+            delayed_payment_basepoint: pk(
+                "0323f45a45412514eede504efa89d59301677482b370dff55a50b950e68db06c1c"
+            ),
+            htlc_basepoint: pk(
+                "02101697180c2763224d0943ba8345a536ebf709491292461bdcb8dfa89a04e919"
+            ),
+            first_per_commitment_point: pk(
+                "03c2674f537e5e5338f2abef2ba19667310b057262e949f19ed845fc06e8d63d6b"
+            ),
+        });
+    }
+}